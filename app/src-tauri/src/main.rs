@@ -1,51 +1,176 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::process::{Command, Child};
-use std::sync::Mutex;
-use tauri::Manager;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{BufRead, BufReader, Read};
+use std::net::TcpStream;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
 use serde::{Deserialize, Serialize};
 
+mod sidecar;
+
+// Trailing stderr lines attached to a BackendError::Exited.
+const STDERR_TAIL_LINES: usize = 20;
+
+// Backend log lines kept around for get_backend_logs.
+const MAX_LOG_LINES: usize = 1000;
+
+/// Default amount of time we're willing to wait for the backend to start accepting
+/// connections on its HTTP port before giving up.
+const READY_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long to sleep between readiness probe attempts.
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(75);
+// Restart backoff starts here and doubles on each attempt, up to MAX_RESTART_BACKOFF.
+const INITIAL_RESTART_BACKOFF: Duration = Duration::from_millis(200);
+const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(5);
+// Give up auto-restarting after this many consecutive failed attempts.
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+/// Debounce window for coalescing bursts of `*.go` file events from editors before
+/// triggering a hot-reload restart.
+const HOT_RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+// Whether a filesystem event touches a `.go` source file, used to filter the dev-mode
+// hot-reload watcher down to changes that actually require a backend restart.
+fn touches_go_file(event: &notify::Event) -> bool {
+    event.paths.iter().any(|p| p.extension().is_some_and(|ext| ext == "go"))
+}
+
+#[cfg(test)]
+mod touches_go_file_tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn go_file_matches() {
+        let event = notify::Event::new(notify::EventKind::Any).add_path(PathBuf::from("main.go"));
+        assert!(touches_go_file(&event));
+    }
+
+    #[test]
+    fn other_extension_does_not_match() {
+        let event = notify::Event::new(notify::EventKind::Any).add_path(PathBuf::from("main.ts"));
+        assert!(!touches_go_file(&event));
+    }
+
+    #[test]
+    fn no_extension_does_not_match() {
+        let event = notify::Event::new(notify::EventKind::Any).add_path(PathBuf::from("Makefile"));
+        assert!(!touches_go_file(&event));
+    }
+}
+
+// Forcefully terminate a process by PID, used by `BackendProcess::stop` to signal the
+// backend without needing mutable access to the `Child` the monitor thread owns.
+fn kill_pid(pid: u32) {
+    #[cfg(unix)]
+    {
+        let _ = Command::new("kill").args(&["-9", &pid.to_string()]).output();
+    }
+    #[cfg(windows)]
+    {
+        let _ = Command::new("taskkill")
+            .args(&["/PID", &pid.to_string(), "/F"])
+            .output();
+    }
+}
+
 // Helper function to extract port number from address string like ":9000" or "localhost:9000"
 fn extract_port(addr: &str) -> Option<u16> {
     addr.split(':').last()
         .and_then(|p| p.parse::<u16>().ok())
 }
 
-// Kill any process using the specified port
-fn kill_process_on_port(port: u16) {
+/// Whether something is currently listening on `127.0.0.1:<port>`.
+fn port_is_held(port: u16) -> bool {
+    TcpStream::connect_timeout(&([127, 0, 0, 1], port).into(), Duration::from_millis(200)).is_ok()
+}
+
+/// PIDs of processes with a socket on `port` (listening or connected), via `lsof`.
+#[cfg(unix)]
+fn pids_on_port(port: u16) -> Vec<i32> {
+    let Ok(output) = Command::new("lsof").args(&["-ti", &format!(":{}", port)]).output() else {
+        return Vec::new();
+    };
+    let Ok(text) = String::from_utf8(output.stdout) else {
+        return Vec::new();
+    };
+    text.trim()
+        .lines()
+        .filter_map(|pid| pid.trim().parse::<i32>().ok())
+        .collect()
+}
+
+/// PIDs of processes with a `LISTENING` socket on `port`, via `netstat -ano`. Each matching
+/// line looks like `TCP    0.0.0.0:9000    0.0.0.0:0    LISTENING    1234`, so the local
+/// address is the 2nd column and the PID is the last.
+#[cfg(windows)]
+fn pids_on_port(port: u16) -> Vec<u32> {
+    let Ok(output) = Command::new("netstat").args(&["-ano"]).output() else {
+        return Vec::new();
+    };
+    let Ok(text) = String::from_utf8(output.stdout) else {
+        return Vec::new();
+    };
+    let suffix = format!(":{}", port);
+    text.lines()
+        .filter(|line| line.contains("LISTENING"))
+        .filter(|line| {
+            line.split_whitespace()
+                .nth(1)
+                .is_some_and(|addr| addr.ends_with(&suffix))
+        })
+        .filter_map(|line| line.split_whitespace().last())
+        .filter_map(|pid| pid.parse::<u32>().ok())
+        .collect()
+}
+
+/// Free `port` for the backend to bind, escalating from a graceful stop to a forceful kill
+/// on Unix and force-killing directly on Windows (which has no portable graceful signal for
+/// an arbitrary foreign process). Returns whether the port was actually freed, so `start()`
+/// can surface a clear error instead of proceeding into a bind failure.
+fn kill_process_on_port(port: u16) -> bool {
     #[cfg(unix)]
     {
-        use std::process::Command;
-        // Try to find and kill process using the port
-        if let Ok(output) = Command::new("lsof")
-            .args(&["-ti", &format!(":{}", port)])
-            .output()
-        {
-            if !output.stdout.is_empty() {
-                if let Ok(pid_str) = String::from_utf8(output.stdout) {
-                    for pid in pid_str.trim().split('\n') {
-                        if let Ok(pid_num) = pid.trim().parse::<i32>() {
-                            let _ = Command::new("kill")
-                                .args(&["-9", &pid_num.to_string()])
-                                .output();
-                            eprintln!("Killed process {} on port {}", pid_num, port);
-                        }
-                    }
-                }
-            }
+        let pids = pids_on_port(port);
+        if pids.is_empty() {
+            return true;
         }
+
+        for pid in &pids {
+            let _ = Command::new("kill").args(&["-TERM", &pid.to_string()]).output();
+        }
+        std::thread::sleep(Duration::from_millis(200));
+        if !port_is_held(port) {
+            return true;
+        }
+
+        eprintln!("Process(es) on port {} ignored SIGTERM, escalating to SIGKILL", port);
+        for pid in &pids {
+            let _ = Command::new("kill").args(&["-KILL", &pid.to_string()]).output();
+        }
+        std::thread::sleep(Duration::from_millis(200));
+        !port_is_held(port)
     }
     #[cfg(windows)]
     {
-        use std::process::Command;
-        // Windows: use netstat and taskkill
-        if let Ok(output) = Command::new("netstat")
-            .args(&["-ano"])
-            .output()
-        {
-            // Parse netstat output to find PID and kill it
-            // Implementation would parse the output here
+        let pids = pids_on_port(port);
+        if pids.is_empty() {
+            return true;
         }
+
+        for pid in &pids {
+            let _ = Command::new("taskkill").args(&["/PID", &pid.to_string(), "/F"]).output();
+        }
+        std::thread::sleep(Duration::from_millis(200));
+        !port_is_held(port)
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        !port_is_held(port)
     }
 }
 
@@ -57,37 +182,280 @@ struct BackendConfig {
     allow_origins: String,
 }
 
+// A captured line of backend output, forwarded as a `backend-log` event and kept in the
+// ring buffer backing `get_backend_logs`.
+#[derive(Debug, Clone, Serialize)]
+struct BackendLogLine {
+    // Generation (see BackendProcess::generation) that produced this line, so
+    // recent_stderr can ignore lines left over from a previous attempt.
+    generation: u64,
+    stream: &'static str, // "stdout" or "stderr"
+    line: String,
+}
+
+// Structured BackendProcess::start failures, so restart_backend can report an actionable
+// message instead of "check console logs for details".
+#[derive(Debug)]
+enum BackendError {
+    Spawn {
+        command: String,
+        args: Vec<String>,
+        source: std::io::Error,
+    },
+    Exited {
+        command: String,
+        args: Vec<String>,
+        status: ExitStatus,
+        stderr_tail: Vec<String>,
+    },
+    Timeout {
+        command: String,
+        args: Vec<String>,
+        port: u16,
+        timeout: Duration,
+    },
+    PortInUse { port: u16 },
+    Other(String),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::Spawn { command, args, source } => {
+                write!(f, "failed to execute `{}`: {}", render_command(command, args), source)
+            }
+            BackendError::Exited { command, args, status, stderr_tail } => {
+                write!(
+                    f,
+                    "process didn't exit successfully: `{}` ({})",
+                    render_command(command, args),
+                    describe_exit_status(status)
+                )?;
+                if !stderr_tail.is_empty() {
+                    write!(f, "\n--- stderr\n{}", stderr_tail.join("\n"))?;
+                }
+                Ok(())
+            }
+            BackendError::Timeout { command, args, port, timeout } => {
+                write!(
+                    f,
+                    "timed out after {:?} waiting for `{}` to listen on port {}",
+                    timeout,
+                    render_command(command, args),
+                    port
+                )
+            }
+            BackendError::PortInUse { port } => {
+                write!(f, "port {} is already in use and could not be freed", port)
+            }
+            BackendError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+impl From<String> for BackendError {
+    fn from(message: String) -> Self {
+        BackendError::Other(message)
+    }
+}
+
+impl From<&str> for BackendError {
+    fn from(message: &str) -> Self {
+        BackendError::Other(message.to_string())
+    }
+}
+
+fn render_command(command: &str, args: &[String]) -> String {
+    if args.is_empty() {
+        command.to_string()
+    } else {
+        format!("{} {}", command, args.join(" "))
+    }
+}
+
+#[cfg(unix)]
+fn describe_exit_status(status: &ExitStatus) -> String {
+    use std::os::unix::process::ExitStatusExt;
+    match status.signal() {
+        Some(signal) => {
+            let name = match signal {
+                6 => " (SIGABRT)",
+                9 => " (SIGKILL)",
+                11 => " (SIGSEGV)",
+                15 => " (SIGTERM)",
+                _ => "",
+            };
+            format!("signal: {}{}", signal, name)
+        }
+        None => format!("exit status: {}", status.code().unwrap_or(-1)),
+    }
+}
+
+#[cfg(not(unix))]
+fn describe_exit_status(status: &ExitStatus) -> String {
+    format!("exit code: {}", status.code().unwrap_or(-1))
+}
+
+// Outcome of wait_for_ready, turned into a BackendError once the caller has command/args
+// context and a stderr tail to attach.
+enum ReadyFailure {
+    Exited(ExitStatus),
+    TimedOut,
+}
+
 struct BackendProcess {
-    child: Option<Child>,
+    // The actual Child is owned by the monitor thread (see spawn_monitor), so stop() only
+    // has the pid to signal it by.
+    child_pid: Option<u32>,
     config: Option<BackendConfig>,
+    // Bumped on every start(). Lets a monitor thread tell a stale child's exit (superseded
+    // by a newer start()) apart from a real crash, and lets recent_stderr ignore log lines
+    // left over from a previous attempt.
+    generation: Arc<AtomicU64>,
+    // Set while stop() is tearing the process down deliberately, so the crash monitor
+    // doesn't treat it as a crash to auto-restart from.
+    user_requested_stop: Arc<AtomicBool>,
+    /// Set while a hot-reload restart is in progress, so overlapping file events don't
+    /// trigger concurrent restarts.
+    restart_in_flight: Arc<AtomicBool>,
+    /// Set once the dev-mode file watcher has been spawned, so repeated `start()` calls
+    /// (e.g. from `restart_backend`) don't stack up duplicate watchers.
+    watcher_started: Arc<AtomicBool>,
+    // Ring buffer backing get_backend_logs / backend-log events; persists across restarts.
+    logs: Arc<Mutex<VecDeque<BackendLogLine>>>,
 }
 
 impl BackendProcess {
     fn new() -> Self {
-        Self { 
-            child: None,
+        Self {
+            child_pid: None,
             config: None,
+            generation: Arc::new(AtomicU64::new(0)),
+            user_requested_stop: Arc::new(AtomicBool::new(false)),
+            restart_in_flight: Arc::new(AtomicBool::new(false)),
+            watcher_started: Arc::new(AtomicBool::new(false)),
+            logs: Arc::new(Mutex::new(VecDeque::with_capacity(MAX_LOG_LINES))),
+        }
+    }
+
+    // Drains reader on its own thread. Stdout and stderr each get a thread so a burst on
+    // one can't block the other, the way reading them sequentially would.
+    fn spawn_log_reader(
+        app: tauri::AppHandle,
+        logs: Arc<Mutex<VecDeque<BackendLogLine>>>,
+        generation: u64,
+        stream: &'static str,
+        reader: impl Read + Send + 'static,
+    ) {
+        std::thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else { break };
+                let entry = BackendLogLine { generation, stream, line };
+
+                if let Ok(mut logs) = logs.lock() {
+                    logs.push_back(entry.clone());
+                    if logs.len() > MAX_LOG_LINES {
+                        logs.pop_front();
+                    }
+                }
+
+                let _ = app.emit("backend-log", &entry);
+            }
+        });
+    }
+
+    /// Poll `127.0.0.1:<port>` until it accepts a connection, bailing out early if `child`
+    /// exits in the meantime or if `timeout` elapses.
+    fn wait_for_ready(child: &mut Child, port: u16, timeout: Duration) -> Result<(), ReadyFailure> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Ok(Some(status)) = child.try_wait() {
+                return Err(ReadyFailure::Exited(status));
+            }
+
+            if TcpStream::connect_timeout(
+                &([127, 0, 0, 1], port).into(),
+                READY_POLL_INTERVAL,
+            )
+            .is_ok()
+            {
+                return Ok(());
+            }
+
+            if Instant::now() >= deadline {
+                return Err(ReadyFailure::TimedOut);
+            }
+
+            std::thread::sleep(READY_POLL_INTERVAL);
         }
     }
 
-    fn start(&mut self, app: &tauri::AppHandle, config: Option<BackendConfig>) -> Result<(), Box<dyn std::error::Error>> {
+    // Trailing captured stderr from the current generation only, so an old attempt's
+    // output can't be blamed on a fresh crash.
+    fn recent_stderr(&self) -> Vec<String> {
+        let current_generation = self.generation.load(Ordering::SeqCst);
+        let Ok(logs) = self.logs.lock() else {
+            return Vec::new();
+        };
+        let mut tail: Vec<String> = logs
+            .iter()
+            .rev()
+            .filter(|entry| entry.generation == current_generation && entry.stream == "stderr")
+            .take(STDERR_TAIL_LINES)
+            .map(|entry| entry.line.clone())
+            .collect();
+        tail.reverse();
+        tail
+    }
+
+    fn ready_failure_to_error(
+        &self,
+        failure: ReadyFailure,
+        command: String,
+        args: Vec<String>,
+        port: u16,
+    ) -> BackendError {
+        match failure {
+            ReadyFailure::Exited(status) => BackendError::Exited {
+                command,
+                args,
+                status,
+                stderr_tail: self.recent_stderr(),
+            },
+            ReadyFailure::TimedOut => BackendError::Timeout {
+                command,
+                args,
+                port,
+                timeout: READY_TIMEOUT,
+            },
+        }
+    }
+
+    fn start(&mut self, app: &tauri::AppHandle, config: Option<BackendConfig>) -> Result<(), BackendError> {
+        // Bump the generation before tearing down any existing process (see the
+        // `generation` field comment).
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
         // Stop any existing backend first
         self.stop();
-        
-        self.config = config.clone();
+
         let config = config.unwrap_or_else(|| BackendConfig {
             backend_addr: "localhost:8081".to_string(),  // Target gRPC backend to inspect
             http_addr: ":9000".to_string(),  // ServiceLens proxy port (90XX range) - where frontend connects
             use_tls: false,
             allow_origins: "http://localhost:5173".to_string(),
         });
-        
+        self.config = Some(config.clone());
+        self.user_requested_stop.store(false, Ordering::SeqCst);
+
         // Kill any process already using the HTTP port BEFORE starting
         if let Some(port) = extract_port(&config.http_addr) {
             eprintln!("Checking for processes on port {}...", port);
-            kill_process_on_port(port);
-            // Give it a moment to release the port
-            std::thread::sleep(std::time::Duration::from_millis(300));
+            if !kill_process_on_port(port) {
+                return Err(BackendError::PortInUse { port });
+            }
         }
         
         if cfg!(debug_assertions) {
@@ -104,10 +472,9 @@ impl BackendProcess {
             let mut cmd = Command::new("go");
             cmd.args(&["run", "."]);
             cmd.current_dir(&backend_dir);
-            // In dev mode, show backend output in console for debugging
-            cmd.stdout(std::process::Stdio::inherit());
-            cmd.stderr(std::process::Stdio::inherit());
-            
+            cmd.stdout(Stdio::piped());
+            cmd.stderr(Stdio::piped());
+
             // Use config from UI (if provided) or fall back to environment variables or defaults
             // Priority: config > environment variable > default
             let backend_addr = config.backend_addr.clone();
@@ -129,37 +496,42 @@ impl BackendProcess {
             cmd.env("GRPS_ALLOW_ORIGINS", allow_origins);
             cmd.env("GRPS_AUTO_ALLOW_DEV_ORIGINS", "true");
             
-            let child = cmd.spawn()?;
-            self.child = Some(child);
-            println!("Backend process started (dev mode: go run)");
-            
-            // Give the backend a moment to start, then check if it's still running
-            std::thread::sleep(std::time::Duration::from_millis(500));
-            if let Some(ref mut child) = self.child {
-                if let Ok(Some(status)) = child.try_wait() {
-                    eprintln!("Backend process exited immediately with status: {:?}", status);
-                    return Err("Backend process failed to start".into());
-                }
+            let command_name = "go".to_string();
+            let command_args = vec!["run".to_string(), ".".to_string()];
+
+            let mut child = cmd.spawn().map_err(|source| BackendError::Spawn {
+                command: command_name.clone(),
+                args: command_args.clone(),
+                source,
+            })?;
+            if let Some(stdout) = child.stdout.take() {
+                Self::spawn_log_reader(app.clone(), Arc::clone(&self.logs), generation, "stdout", stdout);
+            }
+            if let Some(stderr) = child.stderr.take() {
+                Self::spawn_log_reader(app.clone(), Arc::clone(&self.logs), generation, "stderr", stderr);
+            }
+
+            let port = extract_port(&http_addr).ok_or("Failed to resolve backend http port")?;
+            if let Err(failure) = Self::wait_for_ready(&mut child, port, READY_TIMEOUT) {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(self.ready_failure_to_error(failure, command_name, command_args, port));
             }
+            self.child_pid = Some(child.id());
+            self.spawn_monitor(app, child, self.config.clone(), generation);
+            self.start_dev_watcher(app, backend_dir);
+            println!("Backend process started (dev mode: go run)");
         } else {
             // Production: use sidecar binary
-            // Determine the correct binary name based on target architecture
-            let target_arch = if cfg!(target_arch = "aarch64") {
-                "aarch64"
-            } else if cfg!(target_arch = "x86_64") {
-                "x86_64"
-            } else {
-                return Err("Unsupported target architecture".into());
-            };
-            
-            let binary_name = if cfg!(target_os = "windows") {
-                format!("backend-{}-pc-windows-msvc.exe", target_arch)
-            } else if cfg!(target_os = "macos") {
-                format!("backend-{}-apple-darwin", target_arch)
-            } else {
-                return Err("Unsupported target OS".into());
-            };
-            
+            let binary_name =
+                sidecar::sidecar_name(std::env::consts::OS, std::env::consts::ARCH).ok_or_else(|| {
+                    format!(
+                        "Unsupported target: {} {}",
+                        std::env::consts::OS,
+                        std::env::consts::ARCH
+                    )
+                })?;
+
             // Look for binary - externalBin places binaries in the same directory as the executable
             // On macOS, this is Contents/MacOS/, and Tauri renames it to just "backend"
             // Try multiple locations in order of preference
@@ -232,10 +604,8 @@ impl BackendProcess {
             eprintln!("  Found backend binary at: {:?}", backend_bin);
 
             let mut cmd = Command::new(&backend_bin);
-            // In production, we can still inherit stderr to see errors in console/logs
-            // stdout can be piped to avoid cluttering, but stderr is important for debugging
-            cmd.stdout(std::process::Stdio::piped());
-            cmd.stderr(std::process::Stdio::inherit()); // Show backend errors in console
+            cmd.stdout(Stdio::piped());
+            cmd.stderr(Stdio::piped());
             // Use config from UI (if provided) or fall back to environment variables or defaults
             // Priority: config > environment variable > default
             let backend_addr = config.backend_addr.clone();
@@ -257,33 +627,165 @@ impl BackendProcess {
             cmd.env("GRPS_ALLOW_ORIGINS", allow_origins);
             cmd.env("GRPS_AUTO_ALLOW_DEV_ORIGINS", "true");
             
-            let child = cmd.spawn()?;
-            self.child = Some(child);
-            println!("Backend process started (production mode)");
-            
-            // Give the backend a moment to start, then check if it's still running
-            std::thread::sleep(std::time::Duration::from_millis(500));
-            if let Some(ref mut child) = self.child {
-                if let Ok(Some(status)) = child.try_wait() {
-                    eprintln!("Backend process exited immediately with status: {:?}", status);
-                    // Try to read stderr to see what went wrong
-                    return Err("Backend process failed to start. Check console logs for details.".into());
-                }
+            let command_name = backend_bin.display().to_string();
+            let command_args: Vec<String> = Vec::new();
+
+            let mut child = cmd.spawn().map_err(|source| BackendError::Spawn {
+                command: command_name.clone(),
+                args: command_args.clone(),
+                source,
+            })?;
+            if let Some(stdout) = child.stdout.take() {
+                Self::spawn_log_reader(app.clone(), Arc::clone(&self.logs), generation, "stdout", stdout);
+            }
+            if let Some(stderr) = child.stderr.take() {
+                Self::spawn_log_reader(app.clone(), Arc::clone(&self.logs), generation, "stderr", stderr);
             }
+
+            let port = extract_port(&http_addr).ok_or("Failed to resolve backend http port")?;
+            if let Err(failure) = Self::wait_for_ready(&mut child, port, READY_TIMEOUT) {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(self.ready_failure_to_error(failure, command_name, command_args, port));
+            }
+            self.child_pid = Some(child.id());
+            self.spawn_monitor(app, child, self.config.clone(), generation);
+            println!("Backend process started (production mode)");
         }
         
         Ok(())
     }
 
-    fn stop(&mut self) {
-        if let Some(mut child) = self.child.take() {
-            // Kill the process
-            if let Err(e) = child.kill() {
-                eprintln!("Failed to kill backend process: {}", e);
+    // Takes ownership of `child` (rather than sharing it) so the blocking wait() below
+    // never contends with stop() for a lock; restarts from `config` with exponential
+    // backoff if the child exits on its own with a non-success status. `generation` is the id start() minted for
+    // this child: stop() signals and returns without waiting for this thread to reap it,
+    // so by the time wait() returns, a newer start() may have already replaced this child
+    // and reset `user_requested_stop` back to false — comparing generations catches that
+    // case instead of mistaking it for a crash.
+    fn spawn_monitor(&self, app: &tauri::AppHandle, child: Child, config: Option<BackendConfig>, generation: u64) {
+        let app = app.clone();
+        let user_requested_stop = Arc::clone(&self.user_requested_stop);
+        let generation_counter = Arc::clone(&self.generation);
+
+        std::thread::spawn(move || {
+            let mut child = child;
+            let status = match child.wait() {
+                Ok(status) => status,
+                Err(_) => return,
+            };
+
+            if generation_counter.load(Ordering::SeqCst) != generation {
+                // A newer start() has already superseded this child; its exit is the
+                // expected result of that replacement, not a crash.
+                return;
             }
-            
-            // Wait for process to exit
-            let _ = child.wait();
+
+            if user_requested_stop.load(Ordering::SeqCst) || status.success() {
+                return;
+            }
+
+            eprintln!("Backend process crashed with status: {:?}", status);
+            let _ = app.emit("backend-crashed", format!("{:?}", status));
+
+            let mut backoff = INITIAL_RESTART_BACKOFF;
+            for attempt in 1..=MAX_RESTART_ATTEMPTS {
+                if user_requested_stop.load(Ordering::SeqCst) {
+                    return;
+                }
+                std::thread::sleep(backoff);
+                backoff = (backoff * 2).min(MAX_RESTART_BACKOFF);
+
+                let Some(state) = app.try_state::<Mutex<BackendProcess>>() else {
+                    return;
+                };
+                let Ok(mut backend) = state.lock() else {
+                    return;
+                };
+                match backend.start(&app, config.clone()) {
+                    Ok(_) => {
+                        println!("Backend auto-restarted after crash (attempt {})", attempt);
+                        let _ = app.emit("backend-restarted", attempt);
+                        return;
+                    }
+                    Err(e) => {
+                        eprintln!("Auto-restart attempt {} failed: {}", attempt, e);
+                    }
+                }
+            }
+
+            eprintln!("Backend auto-restart giving up after {} attempts", MAX_RESTART_ATTEMPTS);
+        });
+    }
+
+    /// Watch `backend_dir` for `*.go` changes and hot-reload the backend process whenever a
+    /// debounced burst of edits settles, instead of requiring a manual `restart_backend`
+    /// call after every edit. Only ever spawned once per `BackendProcess`.
+    fn start_dev_watcher(&self, app: &tauri::AppHandle, backend_dir: std::path::PathBuf) {
+        if self.watcher_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let app = app.clone();
+        let restart_in_flight = Arc::clone(&self.restart_in_flight);
+
+        std::thread::spawn(move || {
+            use notify::Watcher;
+
+            let (tx, rx) = std::sync::mpsc::channel();
+            let mut watcher = match notify::recommended_watcher(tx) {
+                Ok(w) => w,
+                Err(e) => {
+                    eprintln!("Failed to create backend file watcher: {}", e);
+                    return;
+                }
+            };
+            if let Err(e) = watcher.watch(&backend_dir, notify::RecursiveMode::Recursive) {
+                eprintln!("Failed to watch backend directory {:?}: {}", backend_dir, e);
+                return;
+            }
+
+            loop {
+                let event = match rx.recv() {
+                    Ok(Ok(event)) => event,
+                    Ok(Err(e)) => {
+                        eprintln!("Backend file watcher error: {}", e);
+                        continue;
+                    }
+                    Err(_) => return, // watcher was dropped
+                };
+
+                if !touches_go_file(&event) {
+                    continue;
+                }
+
+                // Coalesce the rest of this burst of edits before acting on it.
+                while rx.recv_timeout(HOT_RELOAD_DEBOUNCE).is_ok() {}
+
+                if restart_in_flight.swap(true, Ordering::SeqCst) {
+                    continue;
+                }
+
+                println!("Detected backend source change, hot-reloading...");
+                if let Some(state) = app.try_state::<Mutex<BackendProcess>>() {
+                    if let Ok(mut backend) = state.lock() {
+                        let config = backend.config.clone();
+                        if let Err(e) = backend.start(&app, config) {
+                            eprintln!("Hot-reload restart failed: {}", e);
+                        }
+                    }
+                }
+                restart_in_flight.store(false, Ordering::SeqCst);
+            }
+        });
+    }
+
+    fn stop(&mut self) {
+        self.user_requested_stop.store(true, Ordering::SeqCst);
+        if let Some(pid) = self.child_pid.take() {
+            // The monitor thread owns the actual `Child` and reaps it once this signal
+            // lands, so we only need to deliver the kill here.
+            kill_pid(pid);
             println!("Backend process stopped");
         }
     }
@@ -321,7 +823,7 @@ fn main() {
             
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![restart_backend])
+        .invoke_handler(tauri::generate_handler![restart_backend, get_backend_logs])
         .on_window_event(|window, event| {
             if let tauri::WindowEvent::CloseRequested { .. } = event {
                 // Get the backend process from app state and stop it
@@ -358,3 +860,17 @@ fn restart_backend(
         Err("Backend state not found".to_string())
     }
 }
+
+// Recent backend log lines, for a UI log panel.
+#[tauri::command]
+fn get_backend_logs(app: tauri::AppHandle) -> Result<Vec<BackendLogLine>, String> {
+    let state = app
+        .try_state::<Mutex<BackendProcess>>()
+        .ok_or("Backend state not found")?;
+    let backend = state.lock().map_err(|e| format!("Failed to lock backend: {}", e))?;
+    let logs = backend
+        .logs
+        .lock()
+        .map_err(|e| format!("Failed to lock backend logs: {}", e))?;
+    Ok(logs.iter().cloned().collect())
+}
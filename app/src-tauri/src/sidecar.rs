@@ -0,0 +1,60 @@
+// Included directly into both `main.rs` (as a module, for the runtime sidecar lookup) and
+// `build.rs` (via `include!`, for locating the right binary in `binaries/`) so the two
+// can't drift apart on what a target's sidecar binary is named.
+
+/// Construct the sidecar binary filename for a given `target_os`/`target_arch` pair (as
+/// reported by `std::env::consts` at runtime, or `CARGO_CFG_TARGET_OS`/`CARGO_CFG_TARGET_ARCH`
+/// in a build script), or `None` if the target isn't one we ship a backend for. Only
+/// `x86_64`/`aarch64` have a backend built for them; any other arch returns `None` just like
+/// an unsupported OS does, rather than formatting a filename that doesn't exist.
+pub fn sidecar_name(target_os: &str, target_arch: &str) -> Option<String> {
+    if !matches!(target_arch, "x86_64" | "aarch64") {
+        return None;
+    }
+
+    match target_os {
+        "windows" => Some(format!("backend-{}-pc-windows-msvc.exe", target_arch)),
+        "macos" => Some(format!("backend-{}-apple-darwin", target_arch)),
+        "linux" => Some(format!("backend-{}-unknown-linux-gnu", target_arch)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linux_x86_64() {
+        assert_eq!(
+            sidecar_name("linux", "x86_64"),
+            Some("backend-x86_64-unknown-linux-gnu".to_string())
+        );
+    }
+
+    #[test]
+    fn macos_aarch64() {
+        assert_eq!(
+            sidecar_name("macos", "aarch64"),
+            Some("backend-aarch64-apple-darwin".to_string())
+        );
+    }
+
+    #[test]
+    fn windows_x86_64() {
+        assert_eq!(
+            sidecar_name("windows", "x86_64"),
+            Some("backend-x86_64-pc-windows-msvc.exe".to_string())
+        );
+    }
+
+    #[test]
+    fn unsupported_arch_returns_none() {
+        assert_eq!(sidecar_name("linux", "riscv64"), None);
+    }
+
+    #[test]
+    fn unsupported_os_returns_none() {
+        assert_eq!(sidecar_name("freebsd", "x86_64"), None);
+    }
+}